@@ -1,10 +1,22 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use petgraph::graph::{Graph, NodeIndex};
-use petgraph::algo::dijkstra;
+use petgraph::EdgeType;
 use plotters::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+mod metrics;
+mod null_model;
+
+// Fixed so bootstrap output is reproducible across runs.
+const BOOTSTRAP_SEED: u64 = 42;
+
+const POWER_LAW_BOOTSTRAP_SAMPLES: usize = 1000;
+const CI_BOOTSTRAP_SAMPLES: usize = 1000;
+const CONFIDENCE_LEVEL: f64 = 0.95;
 
 #[derive(Debug)]
 struct JobCategory {
@@ -19,11 +31,52 @@ fn main() -> Result<(), Box<dyn Error>> {
     let degrees = calculate_degrees(&graph);
     let two_hop_neighbors = calculate_two_hop_neighbors(&graph);
 
-    analyze_distribution("Degree Distribution", &degrees);
+    let degree_fit = analyze_distribution("Degree Distribution", &degrees);
     analyze_distribution("Two-Hop Neighbors Distribution", &two_hop_neighbors);
 
-    plot_distribution("Degree Distribution", &degrees, "degree_distribution.png")?;
-    plot_distribution("Two-Hop Neighbors Distribution", &two_hop_neighbors, "two_hop_distribution.png")?;
+    plot_distribution("Degree Distribution", &degrees, "degree_distribution.png", Kernel::Gaussian)?;
+    plot_distribution("Two-Hop Neighbors Distribution", &two_hop_neighbors, "two_hop_distribution.png", Kernel::Epanechnikov)?;
+
+    let (_, average_clustering) = metrics::clustering_coefficients(&graph);
+    println!("Average Clustering Coefficient: {:.4}", average_clustering);
+
+    let components = metrics::connected_components(&graph);
+    let mut component_sizes: Vec<usize> = components.iter().map(|c| c.len()).collect();
+    component_sizes.sort_unstable_by(|a, b| b.cmp(a));
+    println!("Connected Components: {} (sizes: {:?})", components.len(), component_sizes);
+
+    let betweenness = metrics::betweenness_centrality(&graph);
+    let top_betweenness = metrics::top_k_by_betweenness(&graph, &betweenness, 5);
+    println!("Top Job Categories by Betweenness Centrality:");
+    for (name, score) in &top_betweenness {
+        println!("  {}: {:.2}", name, score);
+    }
+
+    if let Some((observed_alpha, _, _)) = degree_fit {
+        let mut null_model_rng = StdRng::seed_from_u64(BOOTSTRAP_SEED);
+        let comparison = null_model::compare_to_null_model(
+            &degrees,
+            average_clustering,
+            observed_alpha,
+            null_model::NULL_MODEL_ENSEMBLE_SIZE,
+            &mut null_model_rng,
+        );
+        println!(
+            "Null Model Comparison (configuration model, {} graphs):",
+            null_model::NULL_MODEL_ENSEMBLE_SIZE
+        );
+        println!(
+            "  Clustering Coefficient: observed {:.4} vs null mean {:.4} ± {:.4} (z = {:.2})",
+            comparison.observed_clustering,
+            comparison.null_clustering_mean,
+            comparison.null_clustering_std_dev,
+            comparison.clustering_z_score
+        );
+        println!(
+            "  Power Law α: observed {:.2} vs null mean {:.2} ± {:.2} (z = {:.2})",
+            comparison.observed_alpha, comparison.null_alpha_mean, comparison.null_alpha_std_dev, comparison.alpha_z_score
+        );
+    }
 
     Ok(())
 }
@@ -75,22 +128,44 @@ fn create_graph(data: &[JobCategory]) -> (Graph<(String, f64), f64>, HashMap<Str
 }
 
 fn calculate_degrees(graph: &Graph<(String, f64), f64>) -> Vec<usize> {
-    graph.node_indices().map(|n| graph.neighbors(n).count()).collect()
+    graph.node_indices().map(|n| graph.neighbors_undirected(n).count()).collect()
 }
 
 fn calculate_two_hop_neighbors(graph: &Graph<(String, f64), f64>) -> Vec<usize> {
     graph.node_indices()
-        .map(|n| {
-            let distances = dijkstra(graph, n, None, |_| 1);
-            distances.values().filter(|&&d| d == 2).count()
-        })
+        .map(|n| neighbors_at_distance(graph, n, 2).len())
         .collect()
 }
 
-fn analyze_distribution(name: &str, data: &[usize]) {
+// Nodes reachable from `source` at exactly distance `k`, via bounded BFS.
+fn neighbors_at_distance<N, E, Ty: EdgeType>(
+    graph: &Graph<N, E, Ty>,
+    source: NodeIndex,
+    k: usize,
+) -> HashSet<NodeIndex> {
+    let mut visited: HashSet<NodeIndex> = HashSet::new();
+    visited.insert(source);
+    let mut frontier: Vec<NodeIndex> = vec![source];
+
+    for _ in 0..k {
+        let mut next_frontier = Vec::new();
+        for &node in &frontier {
+            for neighbor in graph.neighbors_undirected(node) {
+                if visited.insert(neighbor) {
+                    next_frontier.push(neighbor);
+                }
+            }
+        }
+        frontier = next_frontier;
+    }
+
+    frontier.into_iter().collect()
+}
+
+fn analyze_distribution(name: &str, data: &[usize]) -> Option<(f64, usize, f64)> {
     if data.is_empty() {
         println!("No data available for {}", name);
-        return;
+        return None;
     }
 
     let total: usize = data.iter().sum();
@@ -98,7 +173,7 @@ fn analyze_distribution(name: &str, data: &[usize]) {
     
     if mean.is_nan() || mean.is_infinite() {
         println!("Mean calculation resulted in NaN or infinite value.");
-        return;
+        return None;
     }
 
     let variance: f64 = data.iter()
@@ -110,67 +185,245 @@ fn analyze_distribution(name: &str, data: &[usize]) {
     
     let std_dev = variance.sqrt();
 
+    let mut rng = StdRng::seed_from_u64(BOOTSTRAP_SEED);
+    let mean_ci = bootstrap_ci(
+        data,
+        |sample| sample.iter().sum::<usize>() as f64 / sample.len() as f64,
+        CI_BOOTSTRAP_SAMPLES,
+        CONFIDENCE_LEVEL,
+        &mut rng,
+    );
+    let std_dev_ci = bootstrap_ci(data, stat_std_dev, CI_BOOTSTRAP_SAMPLES, CONFIDENCE_LEVEL, &mut rng);
+
     println!("{} Analysis:", name);
-    println!("  Mean: {:.2}", mean);
-    println!("  Standard Deviation: {:.2}", std_dev);
+    println!("  Mean: {:.2} (95% CI: [{:.2}, {:.2}])", mean, mean_ci.0, mean_ci.1);
+    println!("  Standard Deviation: {:.2} (95% CI: [{:.2}, {:.2}])", std_dev, std_dev_ci.0, std_dev_ci.1);
     println!("  Minimum: {}", data.iter().min().unwrap());
     println!("  Maximum: {}", data.iter().max().unwrap());
 
-    let log_data: Vec<f64> = data.iter().filter(|&&x| x > 0).map(|&x| (x as f64).ln()).collect();
-    
-    if !log_data.is_empty() {
-        let (alpha, x_min) = estimate_power_law_parameters(&log_data);
-        println!("  Estimated Power Law Parameters:");
-        println!("    α: {:.2}", alpha);
-        println!("    x_min: {:.2}", x_min.exp());
-
-        let ks_statistic = kolmogorov_smirnov_test(&log_data, alpha, x_min);
-        println!("  Kolmogorov-Smirnov Statistic: {:.4}", ks_statistic);
-        
-        if ks_statistic < 0.05 {
-            println!("  The distribution closely follows a power-law (p < 0.05)");
-        } else if ks_statistic < 0.1 {
-            println!("  The distribution moderately follows a power-law (0.05 ≤ p < 0.1)");
-        } else {
-            println!("  The distribution does not strongly follow a power-law (p ≥ 0.1)");
+    let fit = estimate_power_law_parameters(data);
+    match fit {
+        Some((alpha, x_min, d)) => {
+            println!("  Estimated Power Law Parameters (Clauset-Shalizi-Newman):");
+            let alpha_ci = bootstrap_ci(
+                data,
+                |sample| {
+                    estimate_power_law_parameters(sample)
+                        .map(|(a, _, _)| a)
+                        .unwrap_or(f64::NAN)
+                },
+                CI_BOOTSTRAP_SAMPLES,
+                CONFIDENCE_LEVEL,
+                &mut rng,
+            );
+            println!("    α: {:.2} (95% CI: [{:.2}, {:.2}])", alpha, alpha_ci.0, alpha_ci.1);
+            println!("    x_min: {}", x_min);
+            println!("  Kolmogorov-Smirnov Statistic: {:.4}", d);
+
+            let p_value = bootstrap_power_law_p_value(
+                data,
+                x_min,
+                alpha,
+                d,
+                POWER_LAW_BOOTSTRAP_SAMPLES,
+                &mut rng,
+            );
+            println!("  Bootstrap Goodness-of-Fit p-value: {:.3}", p_value);
+
+            if p_value > 0.1 {
+                println!("  The power-law hypothesis is plausible (p > 0.1)");
+            } else {
+                println!("  The power-law hypothesis is not plausible (p ≤ 0.1)");
+            }
         }
-        
-    } else {
-        println!("Insufficient data for power law analysis");
+        None => println!("Insufficient data for power law analysis"),
+    }
+
+    fit
+}
+
+fn stat_std_dev(sample: &[usize]) -> f64 {
+    let n = sample.len() as f64;
+    let mean = sample.iter().sum::<usize>() as f64 / n;
+    let variance = sample.iter().map(|&x| {
+        let diff = x as f64 - mean;
+        diff * diff
+    }).sum::<f64>() / n;
+    variance.sqrt()
+}
+
+fn bootstrap_ci(
+    data: &[usize],
+    stat_fn: impl Fn(&[usize]) -> f64,
+    n_resamples: usize,
+    confidence: f64,
+    rng: &mut StdRng,
+) -> (f64, f64) {
+    let n = data.len();
+    let mut stats: Vec<f64> = (0..n_resamples)
+        .map(|_| {
+            let resample: Vec<usize> = (0..n).map(|_| data[rng.gen_range(0..n)]).collect();
+            stat_fn(&resample)
+        })
+        .filter(|x| !x.is_nan())
+        .collect();
+
+    if stats.is_empty() {
+        return (f64::NAN, f64::NAN);
     }
+
+    stats.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let tail = (1.0 - confidence) / 2.0;
+    let lower_idx = ((tail * stats.len() as f64) as usize).min(stats.len() - 1);
+    let upper_idx = (((1.0 - tail) * stats.len() as f64) as usize).min(stats.len() - 1);
+    (stats[lower_idx], stats[upper_idx])
 }
 
-fn estimate_power_law_parameters(log_data: &[f64]) -> (f64, f64) {
-    let n = log_data.len() as f64;
-    
-   if n <= 1.0 {
-       return (f64::NAN, f64::NAN); 
-   }
-   
-   let sum: f64 = log_data.iter().sum();
-   let x_min = log_data.iter().fold(f64::INFINITY, |a, &b| a.min(b));
-   let alpha = 1.0 + n / (sum - n * x_min.ln());
-   (alpha, x_min)
+// Clauset-Shalizi-Newman estimator: tries every candidate x_min and keeps
+// the (x_min, alpha) pair that minimizes the KS distance to the data.
+fn estimate_power_law_parameters(data: &[usize]) -> Option<(f64, usize, f64)> {
+    let positive: Vec<usize> = data.iter().copied().filter(|&x| x > 0).collect();
+    if positive.is_empty() {
+        return None;
+    }
+
+    let mut candidates = positive.clone();
+    candidates.sort_unstable();
+    candidates.dedup();
+
+    let mut best: Option<(f64, usize, f64)> = None;
+    for x_min in candidates {
+        let tail: Vec<usize> = positive.iter().copied().filter(|&x| x >= x_min).collect();
+        let n = tail.len() as f64;
+        if n < 2.0 {
+            continue;
+        }
+
+        let sum_ln: f64 = tail.iter().map(|&x| (x as f64 / (x_min as f64 - 0.5)).ln()).sum();
+        if sum_ln <= 0.0 {
+            continue;
+        }
+
+        let alpha = 1.0 + n / sum_ln;
+        let d = kolmogorov_smirnov_distance(&tail, x_min, alpha);
+
+        if best.is_none_or(|(_, _, best_d)| d < best_d) {
+            best = Some((alpha, x_min, d));
+        }
+    }
+
+    best
 }
 
-fn kolmogorov_smirnov_test(log_data: &[f64], alpha: f64, x_min: f64) -> f64 {
-   let sorted_data: Vec<f64> = log_data.iter().filter(|&&x| x >= x_min).cloned().collect();
-   let m = sorted_data.len();
+fn kolmogorov_smirnov_distance(tail: &[usize], x_min: usize, alpha: f64) -> f64 {
+    let mut sorted_tail = tail.to_vec();
+    sorted_tail.sort_unstable();
+    let n = sorted_tail.len();
 
-   sorted_data.iter().enumerate().map(|(i, &x)| {
-       let theoretical_cdf = 1.0 - ((x / x_min).powf(-alpha + 1.0));
-       let empirical_cdf = (i + 1) as f64 / m as f64;
-       (theoretical_cdf - empirical_cdf).abs()
-   }).fold(0.0, f64::max)
+    sorted_tail.iter().enumerate().map(|(i, &x)| {
+        let empirical_cdf = (i + 1) as f64 / n as f64;
+        let theoretical_cdf = 1.0 - (x as f64 / x_min as f64).powf(1.0 - alpha);
+        (theoretical_cdf - empirical_cdf).abs()
+    }).fold(0.0, f64::max)
 }
 
-fn plot_distribution(title: &str, data: &[usize], filename: &str) -> Result<(), Box<dyn Error>> {
-   let root = BitMapBackend::new(filename, (800, 600)).into_drawing_area();
+fn bootstrap_power_law_p_value(
+    data: &[usize],
+    x_min: usize,
+    alpha: f64,
+    d_obs: f64,
+    n_synthetic: usize,
+    rng: &mut StdRng,
+) -> f64 {
+    let below_x_min: Vec<usize> = data.iter().copied().filter(|&x| x < x_min).collect();
+    let n_total = data.len();
+    let p_tail = data.iter().filter(|&&x| x >= x_min).count() as f64 / n_total as f64;
+
+    let mut extreme_count = 0;
+    for _ in 0..n_synthetic {
+        let synthetic: Vec<usize> = (0..n_total)
+            .map(|_| {
+                if rng.gen::<f64>() < p_tail {
+                    sample_power_law(x_min, alpha, rng)
+                } else if !below_x_min.is_empty() {
+                    below_x_min[rng.gen_range(0..below_x_min.len())]
+                } else {
+                    x_min
+                }
+            })
+            .collect();
+
+        if let Some((_, _, d_synth)) = estimate_power_law_parameters(&synthetic) {
+            if d_synth >= d_obs {
+                extreme_count += 1;
+            }
+        }
+    }
+
+    extreme_count as f64 / n_synthetic as f64
+}
+
+fn sample_power_law(x_min: usize, alpha: f64, rng: &mut StdRng) -> usize {
+    let u: f64 = rng.gen();
+    let x = x_min as f64 * (1.0 - u).powf(1.0 / (1.0 - alpha));
+    x.round().max(x_min as f64) as usize
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Kernel {
+    Gaussian,
+    Epanechnikov,
+}
+
+impl Kernel {
+    fn evaluate(&self, u: f64) -> f64 {
+        match self {
+            Kernel::Gaussian => (-0.5 * u * u).exp() / (2.0 * std::f64::consts::PI).sqrt(),
+            Kernel::Epanechnikov => {
+                if u.abs() <= 1.0 {
+                    0.75 * (1.0 - u * u)
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+// Silverman's rule of thumb: h = 1.06 * sigma * n^(-1/5)
+fn silverman_bandwidth(data: &[f64]) -> f64 {
+    let n = data.len() as f64;
+    let mean = data.iter().sum::<f64>() / n;
+    let variance = data.iter().map(|&x| (x - mean).powi(2)).sum::<f64>() / n;
+    let h = variance.sqrt() * 1.06 * n.powf(-1.0 / 5.0);
+    // Zero variance (all points equal) would otherwise divide by zero below.
+    if h > 0.0 {
+        h
+    } else {
+        1.0
+    }
+}
+
+fn kernel_density_estimate(data: &[f64], kernel: Kernel, grid: &[f64]) -> Vec<f64> {
+    let n = data.len() as f64;
+    let h = silverman_bandwidth(data);
+    grid.iter()
+        .map(|&x| {
+            data.iter().map(|&xi| kernel.evaluate((x - xi) / h)).sum::<f64>() / (n * h)
+        })
+        .collect()
+}
+
+fn plot_distribution(title: &str, data: &[usize], filename: &str, kernel: Kernel) -> Result<(), Box<dyn Error>> {
+   let root = BitMapBackend::new(filename, (1200, 600)).into_drawing_area();
    root.fill(&WHITE)?;
+   let panels = root.split_evenly((1, 2));
+   let (scatter_area, density_area) = (&panels[0], &panels[1]);
 
    let max_value = *data.iter().max().unwrap_or(&1) as f64;
-   let mut chart = ChartBuilder::on(&root)
-       .caption(title, ("sans-serif", 40).into_font())
+   let mut chart = ChartBuilder::on(scatter_area)
+       .caption(title, ("sans-serif", 30).into_font())
        .margin(5)
        .x_label_area_size(30)
        .y_label_area_size(30)
@@ -180,15 +433,39 @@ fn plot_distribution(title: &str, data: &[usize], filename: &str) -> Result<(),
 
    chart.draw_series(
        data.iter().enumerate().map(|(i, &count)| {
-           Circle::new((count as f64, (i + 1) as f64), 2, &RED.mix(0.5))
+           Circle::new((count as f64, (i + 1) as f64), 2, RED.mix(0.5))
        })
    )?;
 
    chart.configure_series_labels()
-       .background_style(&WHITE.mix(0.8))
-       .border_style(&BLACK)
+       .background_style(WHITE.mix(0.8))
+       .border_style(BLACK)
        .draw()?;
 
+   let values: Vec<f64> = data.iter().map(|&x| x as f64).collect();
+   if values.len() > 1 {
+       let min_value = values.iter().cloned().fold(f64::INFINITY, f64::min);
+       let max_value = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+       let grid: Vec<f64> = (0..200)
+           .map(|i| min_value + (max_value - min_value) * i as f64 / 199.0)
+           .collect();
+       let density = kernel_density_estimate(&values, kernel, &grid);
+       let max_density = density.iter().cloned().fold(0.0, f64::max);
+
+       let mut density_chart = ChartBuilder::on(density_area)
+           .caption(format!("{} (KDE)", title), ("sans-serif", 30).into_font())
+           .margin(5)
+           .x_label_area_size(30)
+           .y_label_area_size(30)
+           .build_cartesian_2d(min_value..max_value, 0.0..(max_density * 1.1).max(f64::MIN_POSITIVE))?;
+
+       density_chart.configure_mesh().draw()?;
+       density_chart.draw_series(LineSeries::new(
+           grid.iter().zip(density.iter()).map(|(&x, &y)| (x, y)),
+           &BLUE,
+       ))?;
+   }
+
    root.present()?;
    Ok(())
 }
@@ -210,8 +487,8 @@ mod tests {
     fn test_create_graph() {
         let data = vec![
             JobCategory { name: "Job1".to_string(), male_percentage: 50.0 },
-            JobCategory { name: "Job2".to_string(), male_percentage: 60.0 },
-            JobCategory { name: "Job3".to_string(), male_percentage: 70.0 },
+            JobCategory { name: "Job2".to_string(), male_percentage: 55.0 },
+            JobCategory { name: "Job3".to_string(), male_percentage: 80.0 },
         ];
         let (graph, node_indices) = create_graph(&data);
         assert_eq!(graph.node_count(), 3);
@@ -221,27 +498,81 @@ mod tests {
 
     #[test]
     fn test_calculate_degrees() {
-        let mut graph = Graph::new();
-        let n1 = graph.add_node("1");
-        let n2 = graph.add_node("2");
-        let n3 = graph.add_node("3");
-        graph.add_edge(n1, n2, ());
-        graph.add_edge(n1, n3, ());
+        let mut graph: Graph<(String, f64), f64> = Graph::new();
+        let n1 = graph.add_node(("1".to_string(), 0.0));
+        let n2 = graph.add_node(("2".to_string(), 0.0));
+        let n3 = graph.add_node(("3".to_string(), 0.0));
+        graph.add_edge(n1, n2, 1.0);
+        graph.add_edge(n1, n3, 1.0);
         let degrees = calculate_degrees(&graph);
         assert_eq!(degrees, vec![2, 1, 1]);
     }
 
     #[test]
     fn test_calculate_two_hop_neighbors() {
-        let mut graph = Graph::new();
-        let n1 = graph.add_node("1");
-        let n2 = graph.add_node("2");
-        let n3 = graph.add_node("3");
-        let n4 = graph.add_node("4");
-        graph.add_edge(n1, n2, ());
-        graph.add_edge(n2, n3, ());
-        graph.add_edge(n3, n4, ());
+        let mut graph: Graph<(String, f64), f64> = Graph::new();
+        let n1 = graph.add_node(("1".to_string(), 0.0));
+        let n2 = graph.add_node(("2".to_string(), 0.0));
+        let n3 = graph.add_node(("3".to_string(), 0.0));
+        let n4 = graph.add_node(("4".to_string(), 0.0));
+        graph.add_edge(n1, n2, 1.0);
+        graph.add_edge(n2, n3, 1.0);
+        graph.add_edge(n3, n4, 1.0);
         let two_hop = calculate_two_hop_neighbors(&graph);
         assert_eq!(two_hop, vec![1, 1, 1, 1]);
     }
+
+    #[test]
+    fn test_estimate_power_law_parameters_on_clean_tail() {
+        let data = vec![1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 4];
+        let (alpha, x_min, d) = estimate_power_law_parameters(&data).unwrap();
+        assert!(alpha > 1.0 && alpha < 10.0);
+        assert!(x_min >= 1);
+        assert!((0.0..=1.0).contains(&d));
+    }
+
+    #[test]
+    fn test_kolmogorov_smirnov_distance_single_point_tail() {
+        // With only x_min itself in the tail, the theoretical CDF at x_min is
+        // 0 but the empirical CDF is 1, so the distance is exactly 1.
+        let d = kolmogorov_smirnov_distance(&[5], 5, 2.0);
+        assert_eq!(d, 1.0);
+    }
+
+    #[test]
+    fn test_bootstrap_power_law_p_value_in_unit_range() {
+        let data = vec![1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 4];
+        let (alpha, x_min, d) = estimate_power_law_parameters(&data).unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        let p_value = bootstrap_power_law_p_value(&data, x_min, alpha, d, 50, &mut rng);
+        assert!((0.0..=1.0).contains(&p_value));
+    }
+
+    #[test]
+    fn test_bootstrap_ci_brackets_known_mean() {
+        let data: Vec<usize> = (1..=100).collect();
+        let mut rng = StdRng::seed_from_u64(0);
+        let ci = bootstrap_ci(
+            &data,
+            |sample| sample.iter().sum::<usize>() as f64 / sample.len() as f64,
+            2000,
+            0.95,
+            &mut rng,
+        );
+        assert!(ci.0 < 50.5 && 50.5 < ci.1);
+    }
+
+    #[test]
+    fn test_silverman_bandwidth_handles_zero_variance() {
+        let h = silverman_bandwidth(&[3.0, 3.0, 3.0, 3.0]);
+        assert!(h > 0.0 && h.is_finite());
+    }
+
+    #[test]
+    fn test_kernel_density_estimate_finite_on_constant_data() {
+        let data = vec![3.0, 3.0, 3.0, 3.0];
+        let grid = vec![3.0, 3.0];
+        let density = kernel_density_estimate(&data, Kernel::Gaussian, &grid);
+        assert!(density.iter().all(|d| d.is_finite()));
+    }
 }
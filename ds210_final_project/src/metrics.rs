@@ -0,0 +1,182 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::EdgeType;
+
+pub fn clustering_coefficients<N, E, Ty: EdgeType>(graph: &Graph<N, E, Ty>) -> (Vec<f64>, f64) {
+    let per_node: Vec<f64> = graph
+        .node_indices()
+        .map(|n| local_clustering_coefficient(graph, n))
+        .collect();
+
+    let average = if per_node.is_empty() {
+        0.0
+    } else {
+        per_node.iter().sum::<f64>() / per_node.len() as f64
+    };
+
+    (per_node, average)
+}
+
+fn local_clustering_coefficient<N, E, Ty: EdgeType>(graph: &Graph<N, E, Ty>, node: NodeIndex) -> f64 {
+    let neighbors: Vec<NodeIndex> = graph.neighbors_undirected(node).collect();
+    let k = neighbors.len();
+    if k < 2 {
+        return 0.0;
+    }
+
+    let mut links = 0;
+    for (i, &a) in neighbors.iter().enumerate() {
+        for &b in neighbors.iter().skip(i + 1) {
+            if graph.neighbors_undirected(a).any(|n| n == b) {
+                links += 1;
+            }
+        }
+    }
+
+    let possible_links = k * (k - 1) / 2;
+    links as f64 / possible_links as f64
+}
+
+pub fn connected_components<N, E, Ty: EdgeType>(graph: &Graph<N, E, Ty>) -> Vec<Vec<NodeIndex>> {
+    let mut visited: HashSet<NodeIndex> = HashSet::new();
+    let mut components = Vec::new();
+
+    for start in graph.node_indices() {
+        if visited.contains(&start) {
+            continue;
+        }
+
+        let mut component = Vec::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(start);
+        visited.insert(start);
+
+        while let Some(node) = queue.pop_front() {
+            component.push(node);
+            for neighbor in graph.neighbors_undirected(node) {
+                if visited.insert(neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        components.push(component);
+    }
+
+    components
+}
+
+pub fn betweenness_centrality<N, E, Ty: EdgeType>(graph: &Graph<N, E, Ty>) -> HashMap<NodeIndex, f64> {
+    let nodes: Vec<NodeIndex> = graph.node_indices().collect();
+    let mut centrality: HashMap<NodeIndex, f64> = nodes.iter().map(|&n| (n, 0.0)).collect();
+
+    for &source in &nodes {
+        let mut stack = Vec::new();
+        let mut predecessors: HashMap<NodeIndex, Vec<NodeIndex>> = HashMap::new();
+        let mut sigma: HashMap<NodeIndex, f64> = nodes.iter().map(|&n| (n, 0.0)).collect();
+        let mut distance: HashMap<NodeIndex, i64> = nodes.iter().map(|&n| (n, -1)).collect();
+
+        sigma.insert(source, 1.0);
+        distance.insert(source, 0);
+
+        let mut queue = VecDeque::new();
+        queue.push_back(source);
+
+        while let Some(v) = queue.pop_front() {
+            stack.push(v);
+            for w in graph.neighbors_undirected(v) {
+                if distance[&w] < 0 {
+                    distance.insert(w, distance[&v] + 1);
+                    queue.push_back(w);
+                }
+                if distance[&w] == distance[&v] + 1 {
+                    sigma.insert(w, sigma[&w] + sigma[&v]);
+                    predecessors.entry(w).or_default().push(v);
+                }
+            }
+        }
+
+        let mut dependency: HashMap<NodeIndex, f64> = nodes.iter().map(|&n| (n, 0.0)).collect();
+        while let Some(w) = stack.pop() {
+            if let Some(preds) = predecessors.get(&w) {
+                for &v in preds {
+                    dependency.insert(v, dependency[&v] + (sigma[&v] / sigma[&w]) * (1.0 + dependency[&w]));
+                }
+            }
+            if w != source {
+                *centrality.get_mut(&w).unwrap() += dependency[&w];
+            }
+        }
+    }
+
+    // Undirected graph: every shortest path is counted once per direction.
+    for value in centrality.values_mut() {
+        *value /= 2.0;
+    }
+
+    centrality
+}
+
+pub fn top_k_by_betweenness<'a>(
+    graph: &'a Graph<(String, f64), f64>,
+    centrality: &HashMap<NodeIndex, f64>,
+    k: usize,
+) -> Vec<(&'a str, f64)> {
+    let mut ranked: Vec<(&str, f64)> = centrality
+        .iter()
+        .map(|(&n, &score)| (graph[n].0.as_str(), score))
+        .collect();
+
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+    ranked.truncate(k);
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_graph(edges: &[(usize, usize)], node_count: usize) -> Graph<(String, f64), f64> {
+        let mut graph = Graph::new();
+        let nodes: Vec<NodeIndex> = (0..node_count)
+            .map(|i| graph.add_node((i.to_string(), 0.0)))
+            .collect();
+        for &(a, b) in edges {
+            graph.add_edge(nodes[a], nodes[b], 1.0);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_clustering_coefficient_triangle() {
+        let graph = make_graph(&[(0, 1), (1, 2), (0, 2)], 3);
+        let (per_node, average) = clustering_coefficients(&graph);
+        assert_eq!(per_node, vec![1.0, 1.0, 1.0]);
+        assert_eq!(average, 1.0);
+    }
+
+    #[test]
+    fn test_clustering_coefficient_path_has_no_triangles() {
+        let graph = make_graph(&[(0, 1), (1, 2)], 3);
+        let (per_node, _) = clustering_coefficients(&graph);
+        assert_eq!(per_node, vec![0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let graph = make_graph(&[(0, 1), (2, 3)], 5);
+        let mut sizes: Vec<usize> = connected_components(&graph).iter().map(|c| c.len()).collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 2, 2]);
+    }
+
+    #[test]
+    fn test_betweenness_centrality_path() {
+        let graph = make_graph(&[(0, 1), (1, 2)], 3);
+        let centrality = betweenness_centrality(&graph);
+        let node_b = graph.node_indices().nth(1).unwrap();
+        assert_eq!(centrality[&node_b], 1.0);
+        assert_eq!(centrality[&graph.node_indices().next().unwrap()], 0.0);
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::Undirected;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::estimate_power_law_parameters;
+use crate::metrics;
+
+pub const NULL_MODEL_ENSEMBLE_SIZE: usize = 200;
+
+pub struct NullModelComparison {
+    pub observed_clustering: f64,
+    pub null_clustering_mean: f64,
+    pub null_clustering_std_dev: f64,
+    pub clustering_z_score: f64,
+    pub observed_alpha: f64,
+    pub null_alpha_mean: f64,
+    pub null_alpha_std_dev: f64,
+    pub alpha_z_score: f64,
+}
+
+pub fn configuration_model(degree_sequence: &[usize], rng: &mut StdRng) -> Graph<(), (), Undirected> {
+    let mut graph = Graph::new_undirected();
+    let nodes: Vec<NodeIndex> = degree_sequence.iter().map(|_| graph.add_node(())).collect();
+
+    let mut stubs: Vec<NodeIndex> = Vec::new();
+    for (&node, &degree) in nodes.iter().zip(degree_sequence) {
+        stubs.extend(std::iter::repeat_n(node, degree));
+    }
+    stubs.shuffle(rng);
+
+    let mut seen_edges: HashSet<(NodeIndex, NodeIndex)> = HashSet::new();
+    for pair in stubs.chunks_exact(2) {
+        let (a, b) = (pair[0], pair[1]);
+        if a == b {
+            continue;
+        }
+        let key = if a < b { (a, b) } else { (b, a) };
+        if seen_edges.insert(key) {
+            graph.add_edge(a, b, ());
+        }
+    }
+
+    graph
+}
+
+pub fn compare_to_null_model(
+    degree_sequence: &[usize],
+    observed_clustering: f64,
+    observed_alpha: f64,
+    n_ensemble: usize,
+    rng: &mut StdRng,
+) -> NullModelComparison {
+    let mut null_clustering = Vec::with_capacity(n_ensemble);
+    let mut null_alpha = Vec::with_capacity(n_ensemble);
+
+    for _ in 0..n_ensemble {
+        let null_graph = configuration_model(degree_sequence, rng);
+
+        let (_, avg_clustering) = metrics::clustering_coefficients(&null_graph);
+        null_clustering.push(avg_clustering);
+
+        let null_degrees: Vec<usize> = null_graph.node_indices().map(|n| null_graph.neighbors(n).count()).collect();
+        if let Some((alpha, _, _)) = estimate_power_law_parameters(&null_degrees) {
+            null_alpha.push(alpha);
+        }
+    }
+
+    let (null_clustering_mean, null_clustering_std_dev) = mean_and_std_dev(&null_clustering);
+    let (null_alpha_mean, null_alpha_std_dev) = mean_and_std_dev(&null_alpha);
+
+    NullModelComparison {
+        observed_clustering,
+        null_clustering_mean,
+        null_clustering_std_dev,
+        clustering_z_score: z_score(observed_clustering, null_clustering_mean, null_clustering_std_dev),
+        observed_alpha,
+        null_alpha_mean,
+        null_alpha_std_dev,
+        alpha_z_score: z_score(observed_alpha, null_alpha_mean, null_alpha_std_dev),
+    }
+}
+
+fn mean_and_std_dev(values: &[f64]) -> (f64, f64) {
+    if values.is_empty() {
+        return (f64::NAN, f64::NAN);
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|&x| (x - mean).powi(2)).sum::<f64>() / n;
+    (mean, variance.sqrt())
+}
+
+fn z_score(observed: f64, mean: f64, std_dev: f64) -> f64 {
+    if std_dev == 0.0 {
+        0.0
+    } else {
+        (observed - mean) / std_dev
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_configuration_model_keeps_nodes_and_bounds_edges() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let degree_sequence = vec![2, 2, 2, 2];
+        let graph = configuration_model(&degree_sequence, &mut rng);
+        assert_eq!(graph.node_count(), degree_sequence.len());
+        assert!(graph.edge_count() <= degree_sequence.iter().sum::<usize>() / 2);
+    }
+
+    #[test]
+    fn test_configuration_model_no_self_loops() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let degree_sequence = vec![3, 3, 3, 3, 3, 3];
+        let graph = configuration_model(&degree_sequence, &mut rng);
+        for edge in graph.edge_indices() {
+            let (a, b) = graph.edge_endpoints(edge).unwrap();
+            assert_ne!(a, b);
+        }
+    }
+
+    #[test]
+    fn test_z_score() {
+        assert_eq!(z_score(5.0, 3.0, 2.0), 1.0);
+        assert_eq!(z_score(3.0, 3.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_mean_and_std_dev() {
+        let (mean, std_dev) = mean_and_std_dev(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert!((mean - 5.0).abs() < 1e-9);
+        assert!((std_dev - 2.0).abs() < 1e-9);
+    }
+}